@@ -14,6 +14,8 @@ from .dig_client import DigClient
 from .propagation_checker import DNSPropagationChecker, PropagationResult, PropagationSummary
 from .updater import DomainCheckerUpdater
 from .update_checker import UpdateChecker, quick_check
+from .export import export_json, export_csv
+from .exceptions import ParseError
 
 __all__ = [
     "DomainChecker",
@@ -28,4 +30,7 @@ __all__ = [
     "DomainCheckerUpdater",
     "UpdateChecker",
     "quick_check",
+    "export_json",
+    "export_csv",
+    "ParseError",
 ]
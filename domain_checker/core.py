@@ -4,7 +4,7 @@ Core domain checker with async bulk processing
 
 import asyncio
 import time
-from typing import List, Optional, Union
+from typing import Dict, List, Optional, Union
 from asyncio_throttle import Throttler
 
 from .models import DomainInfo, LookupResult, BulkLookupResult
@@ -20,7 +20,12 @@ class DomainChecker:
     def __init__(self, 
                  timeout: int = 30,
                  max_concurrent: int = 10,
-                 rate_limit: float = 1.0):
+                 rate_limit: float = 1.0,
+                 enable_cache: bool = False,
+                 cache_ttl: int = 3600,
+                 cache_size: int = 1000,
+                 user_agent: Optional[str] = None,
+                 rdap_headers: Optional[Dict[str, str]] = None):
         """
         Initialize domain checker
         
@@ -28,13 +33,25 @@ class DomainChecker:
             timeout: Timeout for individual lookups in seconds
             max_concurrent: Maximum concurrent lookups
             rate_limit: Rate limit in requests per second
+            enable_cache: Cache successful RDAP lookups in memory
+            cache_ttl: Cache TTL in seconds
+            cache_size: Maximum number of cached domains
+            user_agent: User-Agent header for RDAP requests
+            rdap_headers: Extra HTTP headers for RDAP requests
         """
         self.timeout = timeout
         self.max_concurrent = max_concurrent
         self.rate_limit = rate_limit
         
         self.whois_client = WhoisClient(timeout=timeout)
-        self.rdap_client = RdapClient(timeout=timeout)
+        self.rdap_client = RdapClient(
+            timeout=timeout,
+            enable_cache=enable_cache,
+            cache_ttl=cache_ttl,
+            cache_size=cache_size,
+            user_agent=user_agent,
+            headers=rdap_headers
+        )
         self.dig_client = DigClient(timeout=timeout)
         self.propagation_checker = DNSPropagationChecker(timeout=timeout)
         self.throttler = Throttler(rate_limit=rate_limit)
@@ -57,6 +74,21 @@ class DomainChecker:
         start_time = time.time()
         
         try:
+            if method in ("auto", "rdap"):
+                # Serve cached RDAP results without waiting on the rate limiter
+                cached_info = self.rdap_client.get_cached(domain)
+                if cached_info is not None:
+                    lookup_time = time.time() - start_time
+                    result = LookupResult(
+                        domain=domain,
+                        success=True,
+                        data=cached_info,
+                        lookup_time=lookup_time,
+                        method="rdap"
+                    )
+                    result.registration_status = self._determine_registration_status(result)
+                    return result
+            
             if method == "auto":
                 # Try RDAP first, fallback to WHOIS
                 try:
@@ -39,6 +39,14 @@ class DigError(DomainLookupError):
         super().__init__(domain, "dig", message, original_error)
 
 
+class ParseError(DomainCheckerError):
+    """Response body could not be parsed"""
+    
+    def __init__(self, url: str, message: str):
+        self.url = url
+        super().__init__(f"Parse error for {url}: {message}")
+
+
 class ConfigurationError(DomainCheckerError):
     """Error in configuration"""
     pass
@@ -0,0 +1,140 @@
+"""
+Export helpers for batches of lookup results
+"""
+
+import csv
+import json
+from datetime import datetime
+from io import StringIO
+from typing import Any, Dict, List, Optional
+
+from .models import LookupResult
+
+# Contact roles on DomainInfo and the fields flattened for each of them
+CONTACT_ROLES = ['registrant', 'admin_contact', 'tech_contact']
+CONTACT_FIELDS = ['name', 'organization', 'email', 'phone', 'address']
+
+# Contact keys mapped to each field: RDAP vCard keys first, then python-whois keys
+CONTACT_FIELD_KEYS = {
+    'name': ['name', 'fn'],
+    'organization': ['organization', 'org'],
+    'email': ['email', 'emails'],
+    'phone': ['phone'],
+    'address': ['address'],
+}
+MAPPED_CONTACT_KEYS = {key for keys in CONTACT_FIELD_KEYS.values() for key in keys}
+
+CSV_COLUMNS = [
+    'domain', 'success', 'method', 'registration_status', 'lookup_time',
+    'registrar', 'creation_date', 'expiration_date', 'updated_date',
+    'status', 'name_servers',
+] + [f"{role}_{field}" for role in CONTACT_ROLES for field in CONTACT_FIELDS + ['raw']] + [
+    'source', 'cached', 'error',
+]
+
+# Separator for list values (status, name servers) inside a single CSV cell
+LIST_SEPARATOR = '; '
+
+
+def export_json(results: List[LookupResult], include_raw: bool = False) -> str:
+    """
+    Serialize lookup results to a JSON array
+
+    Args:
+        results: Lookup results to export
+        include_raw: Include raw WHOIS/RDAP/DIG responses
+
+    Returns:
+        JSON string with one object per result and ISO-8601 dates
+    """
+    exclude = None if include_raw else {'data': {'raw_data'}}
+    data = [result.model_dump(mode='json', exclude=exclude) for result in results]
+    return json.dumps(data, indent=2)
+
+
+def export_csv(results: List[LookupResult]) -> str:
+    """
+    Serialize lookup results to a flat CSV table
+
+    List fields are joined with "; " and each contact is spread over
+    <role>_<field> columns (e.g. registrant_email). Contact data that does not
+    map to a field (such as unparsed WHOIS contact text) goes in <role>_raw.
+    Nested values are JSON-encoded. Missing values are empty.
+
+    Args:
+        results: Lookup results to export
+
+    Returns:
+        CSV string with a header row
+    """
+    output = StringIO()
+    writer = csv.DictWriter(output, fieldnames=CSV_COLUMNS)
+    writer.writeheader()
+
+    for result in results:
+        writer.writerow(_flatten_result(result))
+
+    return output.getvalue()
+
+
+def _flatten_result(result: LookupResult) -> Dict[str, Any]:
+    """Flatten a LookupResult into a single CSV row"""
+    row: Dict[str, Any] = {
+        'domain': result.domain,
+        'success': result.success,
+        'method': result.method,
+        'registration_status': result.registration_status or '',
+        'lookup_time': f"{result.lookup_time:.3f}",
+        'error': result.error or '',
+    }
+
+    info = result.data
+    if not info:
+        return row
+
+    row.update({
+        'registrar': info.registrar or '',
+        'creation_date': _format_date(info.creation_date),
+        'expiration_date': _format_date(info.expiration_date),
+        'updated_date': _format_date(info.updated_date),
+        'status': LIST_SEPARATOR.join(info.status),
+        'name_servers': LIST_SEPARATOR.join(info.name_servers),
+        'source': info.source,
+        'cached': info.cached,
+    })
+
+    for role in CONTACT_ROLES:
+        contact = getattr(info, role) or {}
+        for field, keys in CONTACT_FIELD_KEYS.items():
+            value = next((contact[key] for key in keys if contact.get(key)), None)
+            row[f"{role}_{field}"] = _format_value(value)
+        row[f"{role}_raw"] = _format_unmapped(contact)
+
+    return row
+
+
+def _format_date(date: Optional[datetime]) -> str:
+    """Format datetime as ISO-8601, or empty string if missing"""
+    return date.isoformat() if date else ''
+
+
+def _format_value(value: Any) -> str:
+    """Format a contact value for a CSV cell"""
+    if value is None:
+        return ''
+    if isinstance(value, dict):
+        return json.dumps(value, default=str)
+    if isinstance(value, (list, tuple)):
+        return LIST_SEPARATOR.join(_format_value(v) for v in value if v)
+    return str(value)
+
+
+def _format_unmapped(contact: Dict[str, Any]) -> str:
+    """Format contact keys that have no dedicated column"""
+    unmapped = {k: v for k, v in contact.items() if k not in MAPPED_CONTACT_KEYS and v}
+    if not unmapped:
+        return ''
+    # WHOIS contacts that could not be parsed arrive as {"raw": "<text>"}
+    if list(unmapped) == ['raw']:
+        return _format_value(unmapped['raw'])
+    return json.dumps(unmapped, default=str)
@@ -22,6 +22,7 @@ class DomainInfo(BaseModel):
     tech_contact: Optional[Dict[str, Any]] = None
     raw_data: Optional[str] = None
     source: str  # "whois" or "rdap"
+    cached: bool = False  # True when served from the RDAP client's cache
 
 
 class LookupResult(BaseModel):
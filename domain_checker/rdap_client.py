@@ -11,7 +11,10 @@ import ssl
 from datetime import datetime
 from typing import Optional, Dict, Any, List, Tuple
 import aiohttp
+from . import __version__
 from .models import DomainInfo
+from .cache import TTLCache
+from .exceptions import ParseError
 
 # IANA RDAP Bootstrap URLs (from TheZacillac/rdap-cli)
 BOOTSTRAP_URLS = {
@@ -21,6 +24,12 @@ BOOTSTRAP_URLS = {
     'asn': 'https://data.iana.org/rdap/asn.json'
 }
 
+DEFAULT_USER_AGENT = f"DomainChecker-RDAP/{__version__} (https://github.com/TheZacillac/rdap-cli)"
+
+# RFC 7480 section 4.2: prefer the RDAP media type, fall back to plain JSON
+# (IANA bootstrap files and some registries only serve application/json)
+DEFAULT_ACCEPT = 'application/rdap+json, application/json;q=0.9'
+
 
 class RdapClient:
     """
@@ -29,13 +38,65 @@ class RdapClient:
     https://github.com/TheZacillac/rdap-cli
     """
     
-    def __init__(self, timeout: int = 30):
+    def __init__(self,
+                 timeout: int = 30,
+                 enable_cache: bool = False,
+                 cache_ttl: int = 3600,
+                 cache_size: int = 1000,
+                 user_agent: Optional[str] = None,
+                 headers: Optional[Dict[str, str]] = None):
+        """
+        Initialize RDAP client
+        
+        Args:
+            timeout: Timeout for HTTP requests in seconds
+            enable_cache: Cache successful lookups in memory
+            cache_ttl: Cache TTL in seconds
+            cache_size: Maximum number of cached domains
+            user_agent: User-Agent header (some registries require contact details)
+            headers: Extra HTTP headers, overriding the defaults on conflict
+        """
         self.timeout = timeout
+        self.headers: Dict[str, str] = {
+            'User-Agent': user_agent or DEFAULT_USER_AGENT,
+            'Accept': DEFAULT_ACCEPT,
+        }
+        if headers:
+            # Header names are case-insensitive; drop any default the caller overrides
+            for name, value in headers.items():
+                for existing in [k for k in self.headers if k.lower() == name.lower()]:
+                    del self.headers[existing]
+                self.headers[name] = value
         self.bootstrap_cache: Dict[str, Any] = {}
+        self.response_cache: Optional[TTLCache] = (
+            TTLCache(ttl=cache_ttl, max_size=cache_size) if enable_cache else None
+        )
+        self._pending_lookups: Dict[str, asyncio.Future] = {}
         self.session = None
+        self._session_loop = None
+        # Number of open `async with` blocks sharing the session
+        self._session_users = 0
     
     async def __aenter__(self):
         """Async context manager entry"""
+        # Overlapping `async with` blocks share one session
+        self._session_users += 1
+        self._get_session()
+        return self
+    
+    async def __aexit__(self, exc_type, exc_val, exc_tb):
+        """Async context manager exit"""
+        # Close the shared session once the last concurrent user has left
+        self._session_users -= 1
+        if self._session_users == 0:
+            await self.close()
+    
+    def _get_session(self) -> aiohttp.ClientSession:
+        """Return the HTTP session, creating it lazily on the running event loop"""
+        loop = asyncio.get_running_loop()
+        if self.session is not None and not self.session.closed and self._session_loop is loop:
+            return self.session
+        
         # Create aiohttp session with keep-alive and connection pooling
         connector = aiohttp.TCPConnector(
             limit=100,  # Total connection pool size
@@ -48,31 +109,42 @@ class RdapClient:
         self.session = aiohttp.ClientSession(
             connector=connector,
             timeout=timeout,
-            headers={'User-Agent': 'DomainChecker-RDAP/1.0 (https://github.com/TheZacillac/rdap-cli)'}
+            headers=self.headers
         )
-        return self
+        self._session_loop = loop
+        return self.session
     
-    async def __aexit__(self, exc_type, exc_val, exc_tb):
-        """Async context manager exit"""
-        if self.session:
-            await self.session.close()
+    async def close(self) -> None:
+        """Close the HTTP session; a new one is created on the next lookup"""
+        session = self.session
+        self.session = None
+        self._session_loop = None
+        if session is not None and not session.closed:
+            await session.close()
     
     async def fetch_url(self, url: str) -> Dict:
         """Fetch JSON data from a URL using aiohttp"""
-        if not self.session:
-            raise Exception("RDAP client session not initialized. Use 'async with RdapClient()'")
+        session = self._get_session()
         
         try:
-            async with self.session.get(url) as response:
+            async with session.get(url) as response:
                 if response.status >= 400:
                     raise Exception(f"HTTP Error {response.status}: {response.reason}")
                 
+                content_type = response.content_type or 'unknown'
                 text = await response.text()
-                return json.loads(text)
+                try:
+                    data = json.loads(text)
+                except json.JSONDecodeError:
+                    raise ParseError(url, f"response is not RDAP JSON (content type: {content_type})")
+                
+                if not isinstance(data, dict):
+                    raise ParseError(url, f"response is not an RDAP object (got JSON {type(data).__name__})")
+                return data
         except aiohttp.ClientError as e:
             raise Exception(f"HTTP Client Error: {str(e)}")
-        except json.JSONDecodeError as e:
-            raise Exception(f"JSON Decode Error: {str(e)}")
+        except ParseError:
+            raise
         except Exception as e:
             raise Exception(f"Error fetching URL: {str(e)}")
     
@@ -177,7 +249,55 @@ class RdapClient:
             
         Returns:
             DomainInfo object with parsed RDAP data
+            
+        Raises:
+            ParseError: If the RDAP server returned a body that is not RDAP JSON
         """
+        if self.response_cache is None:
+            return await self._lookup_uncached(domain)
+        
+        cache_key = self._cache_key(domain)
+        cached_info = self.get_cached(domain)
+        if cached_info is not None:
+            return cached_info
+        
+        # Join an identical lookup that is already in flight (e.g. duplicates in a bulk run)
+        pending = self._pending_lookups.get(cache_key)
+        if pending is not None:
+            try:
+                shared_info = await asyncio.shield(pending)
+                return shared_info.model_copy(deep=True)
+            except asyncio.CancelledError:
+                # Re-raise our own cancellation; if the owning lookup was cancelled, query ourselves
+                if not pending.cancelled():
+                    raise
+        
+        future = asyncio.get_running_loop().create_future()
+        self._pending_lookups[cache_key] = future
+        try:
+            try:
+                domain_info = await self._lookup_uncached(domain)
+            except ParseError as e:
+                # Hand the error to joined lookups; mark it retrieved in case there are none
+                future.set_exception(e)
+                future.exception()
+                raise
+            snapshot = domain_info.model_copy(deep=True)
+            
+            # Only cache successful parses; errors and 404s are retried on the next lookup
+            if not snapshot.raw_data.startswith("Error"):
+                self.response_cache.set(cache_key, snapshot)
+            
+            future.set_result(snapshot)
+            return domain_info
+        finally:
+            if not future.done():
+                future.cancel()
+            if self._pending_lookups.get(cache_key) is future:
+                del self._pending_lookups[cache_key]
+    
+    async def _lookup_uncached(self, domain: str) -> DomainInfo:
+        """Query the RDAP server and parse the response, bypassing the cache"""
         try:
             # Perform RDAP query using the framework
             rdap_data = await self.query_rdap(domain)
@@ -185,6 +305,8 @@ class RdapClient:
             # Parse the response
             return self._parse_rdap_data(domain, rdap_data)
             
+        except ParseError:
+            raise
         except Exception as e:
             # If RDAP fails, return minimal info
             return DomainInfo(
@@ -193,6 +315,58 @@ class RdapClient:
                 raw_data=f"Error: {str(e)}"
             )
     
+    async def lookup_async(self, domain: str) -> DomainInfo:
+        """
+        Perform RDAP lookup without an enclosing `async with` block
+        
+        Safe to run concurrently (e.g. with asyncio.gather). All calls reuse one
+        keep-alive HTTP session on the caller's event loop, which stays open
+        until `await client.close()`.
+        
+        Args:
+            domain: Domain name to lookup
+            
+        Returns:
+            DomainInfo object with parsed RDAP data
+            
+        Raises:
+            ParseError: If the RDAP server returned a body that is not RDAP JSON
+        """
+        # Count as a session user so an overlapping `async with` exit doesn't close it mid-request
+        self._session_users += 1
+        try:
+            return await self.lookup(domain)
+        finally:
+            self._session_users -= 1
+    
+    def get_cached(self, domain: str) -> Optional[DomainInfo]:
+        """
+        Return a fresh cached result for a domain without touching the network
+        
+        Args:
+            domain: Domain name to look up in the cache
+            
+        Returns:
+            Copy of the cached DomainInfo with `cached=True`, or None on a miss
+        """
+        if self.response_cache is None:
+            return None
+        
+        cached_info = self.response_cache.get(self._cache_key(domain))
+        if cached_info is None:
+            return None
+        return cached_info.model_copy(deep=True, update={'cached': True})
+    
+    def _cache_key(self, query: str) -> str:
+        """Build a cache key that keeps domain, IP and ASN queries apart"""
+        query_type, normalized_query = self.detect_query_type(query)
+        return f"{query_type}:{normalized_query.rstrip('.')}"
+    
+    def clear_cache(self) -> None:
+        """Remove all cached lookup results"""
+        if self.response_cache is not None:
+            self.response_cache.clear()
+    
     def _parse_rdap_data(self, domain: str, rdap_data: Dict) -> DomainInfo:
         """Parse RDAP response into DomainInfo"""
         try: